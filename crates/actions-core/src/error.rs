@@ -0,0 +1,64 @@
+//! The crate-level error type returned by `get_input`, `get_boolean_input`,
+//! `export_variable`, `save_state`, `get_id_token` and the `Summary` writer.
+//! The crate's other public functions return `Box<dyn Error>`.
+
+use std::error::Error;
+use std::fmt;
+
+/// Errors that can occur while interacting with the Actions runner.
+#[derive(Debug)]
+pub enum CoreError {
+    /// A `required: Some(true)` input was empty.
+    MissingRequiredInput { name: String },
+    /// An input meant to be parsed as a boolean was not one of
+    /// `true | True | TRUE | false | False | FALSE`.
+    InvalidBooleanInput { name: String, value: String },
+    /// An I/O failure, e.g. writing a workflow command file.
+    Io(std::io::Error),
+    /// An environment variable was missing or not valid Unicode.
+    Env(std::env::VarError),
+    /// Fetching or parsing an OIDC token failed.
+    Oidc(String),
+    /// Any other failure, preserved as its `Display` message.
+    Other(String),
+}
+
+impl fmt::Display for CoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoreError::MissingRequiredInput { name } => write!(f, "Input required and not supplied: {name}"),
+            CoreError::InvalidBooleanInput { name, value } => write!(
+                f,
+                "Input does not meet YAML 1.2 \"Core Schema\" specification: {name}\nSupport boolean input list: `true | True | TRUE | false | False | FALSE`, got `{value}`"
+            ),
+            CoreError::Io(err) => write!(f, "{err}"),
+            CoreError::Env(err) => write!(f, "{err}"),
+            CoreError::Oidc(message) => write!(f, "{message}"),
+            CoreError::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl Error for CoreError {}
+
+impl From<std::io::Error> for CoreError {
+    fn from(err: std::io::Error) -> Self {
+        CoreError::Io(err)
+    }
+}
+
+impl From<std::env::VarError> for CoreError {
+    fn from(err: std::env::VarError) -> Self {
+        CoreError::Env(err)
+    }
+}
+
+impl From<Box<dyn Error>> for CoreError {
+    fn from(err: Box<dyn Error>) -> Self {
+        CoreError::Other(err.to_string())
+    }
+}
+
+// `CoreError` implements `std::error::Error`, so the standard library's
+// blanket `impl<E: Error> From<E> for Box<dyn Error>` already covers
+// `CoreError -> Box<dyn Error>`; an explicit impl here would conflict with it.
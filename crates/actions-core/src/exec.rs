@@ -0,0 +1,207 @@
+//! Run external commands and capture their output, modeled on
+//! [@actions/exec](https://www.npmjs.com/package/@actions/exec).
+
+use crate::{debug, info, is_debug};
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::Mutex;
+use std::thread;
+
+/// Options controlling how [`exec`] runs a command.
+///
+/// The stdout/stderr callbacks are wrapped in a [`Mutex`] rather than taking
+/// `&mut self` so that `exec` can read both streams concurrently on separate
+/// threads without blocking a child that fills one pipe's OS buffer while the
+/// other goes unread.
+#[derive(Default)]
+pub struct ExecOptions {
+    pub cwd: Option<PathBuf>,
+    pub env: Option<HashMap<String, String>>,
+    pub silent: bool,
+    pub ignore_return_code: bool,
+    pub on_stdout: Option<Mutex<Box<dyn FnMut(&str) + Send>>>,
+    pub on_stderr: Option<Mutex<Box<dyn FnMut(&str) + Send>>>,
+}
+
+/// The captured result of [`get_exec_output`].
+pub struct ExecOutput {
+    pub exit_code: i32,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Runs `command` with `args`, streaming stdout/stderr line-by-line through
+/// [`info`]/[`debug`] unless `options.silent` is set, and returns the
+/// resulting [`ExitStatus`].
+///
+/// stdout and stderr are read on separate threads so that a child writing
+/// heavily to one stream (e.g. `git clone`/`docker build` progress on
+/// stderr) can't block on a full pipe buffer while the other stream goes
+/// unread.
+///
+/// Returns `Err` if the process fails to spawn, or if it exits with a
+/// non-zero status and `options.ignore_return_code` is `false`.
+pub fn exec(
+    command: &str,
+    args: &[&str],
+    options: &ExecOptions,
+) -> Result<ExitStatus, Box<dyn Error>> {
+    let argv = std::iter::once(command)
+        .chain(args.iter().copied())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if !options.silent {
+        info(&format!("[command]{argv}"));
+    }
+
+    let mut cmd = Command::new(command);
+    cmd.args(args);
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+    if let Some(cwd) = &options.cwd {
+        cmd.current_dir(cwd);
+    }
+    if let Some(env) = &options.env {
+        cmd.envs(env);
+    }
+
+    let mut child = cmd.spawn()?;
+    let stdout = child.stdout.take().expect("stdout was piped");
+    let stderr = child.stderr.take().expect("stderr was piped");
+
+    thread::scope(|scope| -> Result<(), Box<dyn Error>> {
+        let stderr_thread = scope.spawn(|| {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                if !options.silent && is_debug() {
+                    let _ = debug(&line);
+                }
+                if let Some(on_stderr) = &options.on_stderr {
+                    (on_stderr.lock().unwrap())(&line);
+                }
+            }
+        });
+
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if !options.silent {
+                info(&line);
+            }
+            if let Some(on_stdout) = &options.on_stdout {
+                (on_stdout.lock().unwrap())(&line);
+            }
+        }
+
+        stderr_thread
+            .join()
+            .map_err(|_| "stderr reader thread panicked".to_string())?;
+        Ok(())
+    })?;
+
+    let status = child.wait()?;
+    if !status.success() && !options.ignore_return_code {
+        return Err(format!(
+            "`{argv}` failed with exit code {} in `{}`",
+            status.code().unwrap_or(-1),
+            options
+                .cwd
+                .as_ref()
+                .map(|cwd| cwd.display().to_string())
+                .unwrap_or_else(|| ".".into())
+        )
+        .into());
+    }
+    Ok(status)
+}
+
+/// Convenience wrapper around [`exec`] that captures stdout/stderr as
+/// `String`s instead of streaming them through callbacks.
+pub fn get_exec_output(
+    command: &str,
+    args: &[&str],
+    options: &ExecOptions,
+) -> Result<ExecOutput, Box<dyn Error>> {
+    use std::sync::Arc;
+
+    let stdout_buf = Arc::new(Mutex::new(String::new()));
+    let stderr_buf = Arc::new(Mutex::new(String::new()));
+
+    let inner = ExecOptions {
+        cwd: options.cwd.clone(),
+        env: options.env.clone(),
+        silent: options.silent,
+        ignore_return_code: options.ignore_return_code,
+        on_stdout: Some(Mutex::new(Box::new({
+            let stdout_buf = stdout_buf.clone();
+            move |line: &str| {
+                let mut buf = stdout_buf.lock().unwrap();
+                buf.push_str(line);
+                buf.push('\n');
+            }
+        }))),
+        on_stderr: Some(Mutex::new(Box::new({
+            let stderr_buf = stderr_buf.clone();
+            move |line: &str| {
+                let mut buf = stderr_buf.lock().unwrap();
+                buf.push_str(line);
+                buf.push('\n');
+            }
+        }))),
+    };
+
+    let status = exec(command, args, &inner)?;
+    // `inner` owns the other `Arc` clone captured by the closures above;
+    // drop it before unwrapping so the strong count is 1.
+    drop(inner);
+
+    Ok(ExecOutput {
+        exit_code: status.code().unwrap_or(-1),
+        stdout: Arc::try_unwrap(stdout_buf).unwrap().into_inner().unwrap(),
+        stderr: Arc::try_unwrap(stderr_buf).unwrap().into_inner().unwrap(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_exec_output_captures_stdout() {
+        let options = ExecOptions::default();
+        let output = get_exec_output("echo", &["hello"], &options).unwrap();
+        assert_eq!(output.exit_code, 0);
+        assert_eq!(output.stdout, "hello\n");
+        assert_eq!(output.stderr, "");
+    }
+
+    #[test]
+    fn get_exec_output_captures_stderr_without_blocking() {
+        let options = ExecOptions::default();
+        let output = get_exec_output(
+            "sh",
+            &["-c", "echo out; echo err 1>&2"],
+            &options,
+        )
+        .unwrap();
+        assert_eq!(output.stdout, "out\n");
+        assert_eq!(output.stderr, "err\n");
+    }
+
+    #[test]
+    fn exec_reports_non_zero_exit_code() {
+        let options = ExecOptions::default();
+        assert!(exec("sh", &["-c", "exit 1"], &options).is_err());
+    }
+
+    #[test]
+    fn exec_ignores_non_zero_exit_code_when_requested() {
+        let options = ExecOptions {
+            ignore_return_code: true,
+            ..ExecOptions::default()
+        };
+        let status = exec("sh", &["-c", "exit 1"], &options).unwrap();
+        assert_eq!(status.code(), Some(1));
+    }
+}
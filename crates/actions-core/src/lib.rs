@@ -1,6 +1,8 @@
 //! [@actions/core](https://www.npmjs.com/package/@actions/core) for Rust projects.
 
 mod command;
+mod error;
+pub mod exec;
 mod file_command;
 mod oidc_utils;
 mod path_utils;
@@ -8,8 +10,9 @@ pub mod platform;
 mod summary;
 mod utils;
 
+pub use crate::error::CoreError;
 pub use crate::path_utils::{to_platform_path, to_posix_path, to_win32_path};
-pub use crate::summary::{MARKDOWN_SUMMARY, SUMMARY};
+pub use crate::summary::{Summary, TableCell, MARKDOWN_SUMMARY, SUMMARY};
 use crate::utils::to_command_value;
 use command::{issue, issue_command, CommandProperties};
 use file_command::{issue_file_command, prepare_key_value_message};
@@ -40,7 +43,7 @@ pub struct AnnotationProperties<'a> {
     pub end_column: Option<u32>,
 }
 
-pub fn export_variable(name: &str, value: Option<String>) -> Result<(), Box<dyn Error>> {
+pub fn export_variable(name: &str, value: Option<String>) -> Result<(), CoreError> {
     let converted_value = to_command_value(value);
     env::set_var(name, converted_value);
     let file_path = env::var("GITHUB_ENV").unwrap_or_default();
@@ -79,12 +82,12 @@ pub fn add_path(input_path: &str) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-pub fn get_input(name: &str, options: Option<&InputOptions>) -> Result<String, Box<dyn Error>> {
+pub fn get_input(name: &str, options: Option<&InputOptions>) -> Result<String, CoreError> {
     let value =
         env::var(format!("INPUT_{}", name.replace(' ', "_").to_uppercase())).unwrap_or_default();
     if let Some(options) = options {
         if options.required.unwrap_or_default() && value.is_empty() {
-            return Err(format!("input {name} required").into());
+            return Err(CoreError::MissingRequiredInput { name: name.into() });
         }
         if options.trim_whitespace.is_some_and(|x| x == false) {
             return Ok(value);
@@ -110,7 +113,7 @@ pub fn get_multiline_input(
 pub fn get_boolean_input(
     name: &str,
     options: Option<&InputOptions>,
-) -> Result<bool, Box<dyn Error>> {
+) -> Result<bool, CoreError> {
     let true_value = vec!["true", "True", "TRUE"];
     let false_value = vec!["false", "False", "FALSE"];
     let value = get_input(name, options)?;
@@ -120,7 +123,10 @@ pub fn get_boolean_input(
     if false_value.contains(&value.as_str()) {
         return Ok(false);
     }
-    Err(format!("{name} not `true | True | TRUE | false | False | FALSE`").into())
+    Err(CoreError::InvalidBooleanInput {
+        name: name.into(),
+        value,
+    })
 }
 
 pub fn set_output(name: &str, value: Option<String>) -> Result<(), Box<dyn Error>> {
@@ -147,6 +153,16 @@ pub fn set_command_echo(enabled: bool) -> Result<(), Box<dyn Error>> {
 }
 
 pub fn set_failed(message: Box<dyn Error>) -> Result<(), Box<dyn Error>> {
+    match message.downcast_ref::<CoreError>() {
+        Some(CoreError::MissingRequiredInput { name }) => {
+            debug(&format!("missing required input: {name}"))?;
+        }
+        Some(CoreError::InvalidBooleanInput { name, .. }) => {
+            debug(&format!("invalid boolean input: {name}"))?;
+        }
+        Some(CoreError::Oidc(_)) => debug("failed to fetch OIDC token")?,
+        _ => {}
+    }
     error(message, None);
     Ok(())
 }
@@ -241,7 +257,7 @@ pub fn group<T, F: FnOnce() -> T>(name: &str, f: F) -> Result<T, Box<dyn Error>>
     Ok(result)
 }
 
-pub fn save_state(name: &str, value: Option<String>) -> Result<(), Box<dyn Error>> {
+pub fn save_state(name: &str, value: Option<String>) -> Result<(), CoreError> {
     let file_path = env::var("GITHUB_STATE").unwrap_or_default();
     if !file_path.is_empty() {
         issue_file_command("STATE", Some(prepare_key_value_message(name, value)?))?;
@@ -259,6 +275,6 @@ pub fn get_state(name: &str) -> Result<String, Box<dyn Error>> {
     Ok(env::var(format!("STATE_{name}")).unwrap_or_default())
 }
 
-pub fn get_id_token(audience: Option<String>) -> Result<String, Box<dyn Error>> {
-    Ok(OidcClient::get_id_token(audience)?)
+pub fn get_id_token(audience: Option<String>) -> Result<String, CoreError> {
+    OidcClient::get_id_token(audience).map_err(|err| CoreError::Oidc(err.to_string()))
 }
@@ -0,0 +1,330 @@
+//! Runner platform detection, including a small `cfg(...)` expression
+//! evaluator borrowed from the
+//! [cargo-platform](https://crates.io/crates/cargo-platform) mini-language.
+
+use std::error::Error;
+use std::fmt;
+
+/// The current runner's operating system, e.g. `"linux"`, `"windows"`, `"macos"`.
+pub fn platform() -> &'static str {
+    std::env::consts::OS
+}
+
+/// The current runner's CPU architecture, e.g. `"x86_64"`, `"aarch64"`.
+pub fn arch() -> &'static str {
+    std::env::consts::ARCH
+}
+
+pub fn is_windows() -> bool {
+    platform() == "windows"
+}
+
+pub fn is_macos() -> bool {
+    platform() == "macos"
+}
+
+pub fn is_linux() -> bool {
+    platform() == "linux"
+}
+
+/// A parsed `cfg(...)` predicate, as used by Cargo's `target.'cfg(...)'`
+/// dependency tables.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Cfg {
+    Name(String),
+    KeyValue(String, String),
+    All(Vec<Cfg>),
+    Any(Vec<Cfg>),
+    Not(Box<Cfg>),
+}
+
+/// An error parsing a `cfg(...)` expression, including the offending token's
+/// byte position in the input.
+#[derive(Debug)]
+pub struct CfgParseError {
+    message: String,
+    position: usize,
+}
+
+impl fmt::Display for CfgParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl Error for CfgParseError {}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Token {
+    Ident(String),
+    String(String),
+    Equals,
+    Comma,
+    LeftParen,
+    RightParen,
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, CfgParseError> {
+    let mut tokens = Vec::new();
+    let chars = input.char_indices().collect::<Vec<_>>();
+    let mut i = 0;
+    while i < chars.len() {
+        let (pos, c) = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        match c {
+            '=' => {
+                tokens.push((Token::Equals, pos));
+                i += 1;
+            }
+            ',' => {
+                tokens.push((Token::Comma, pos));
+                i += 1;
+            }
+            '(' => {
+                tokens.push((Token::LeftParen, pos));
+                i += 1;
+            }
+            ')' => {
+                tokens.push((Token::RightParen, pos));
+                i += 1;
+            }
+            '"' => {
+                let start = pos;
+                i += 1;
+                let mut value = String::new();
+                let mut closed = false;
+                while i < chars.len() {
+                    let (_, c) = chars[i];
+                    i += 1;
+                    if c == '"' {
+                        closed = true;
+                        break;
+                    }
+                    value.push(c);
+                }
+                if !closed {
+                    return Err(CfgParseError {
+                        message: "unterminated string".into(),
+                        position: start,
+                    });
+                }
+                tokens.push((Token::String(value), start));
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let start = pos;
+                let mut ident = String::new();
+                while i < chars.len() {
+                    let (_, c) = chars[i];
+                    if c.is_alphanumeric() || c == '_' {
+                        ident.push(c);
+                        i += 1;
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push((Token::Ident(ident), start));
+            }
+            _ => {
+                return Err(CfgParseError {
+                    message: format!("unexpected character `{c}`"),
+                    position: pos,
+                });
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&(Token, usize)> {
+        self.tokens.get(self.pos)
+    }
+
+    fn bump(&mut self) -> Option<(Token, usize)> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: &Token) -> Result<(), CfgParseError> {
+        match self.bump() {
+            Some((token, _)) if token == *expected => Ok(()),
+            Some((token, pos)) => Err(CfgParseError {
+                message: format!("expected `{expected:?}`, found `{token:?}`"),
+                position: pos,
+            }),
+            None => Err(CfgParseError {
+                message: format!("expected `{expected:?}`, found end of input"),
+                position: self.tokens.last().map(|(_, pos)| *pos + 1).unwrap_or(0),
+            }),
+        }
+    }
+
+    fn parse_cfg(&mut self) -> Result<Cfg, CfgParseError> {
+        match self.bump() {
+            Some((Token::Ident(name), pos)) => match name.as_str() {
+                "all" => Ok(Cfg::All(self.parse_list()?)),
+                "any" => Ok(Cfg::Any(self.parse_list()?)),
+                "not" => {
+                    self.expect(&Token::LeftParen)?;
+                    let inner = self.parse_cfg()?;
+                    self.expect(&Token::RightParen)?;
+                    Ok(Cfg::Not(Box::new(inner)))
+                }
+                _ => {
+                    if let Some((Token::Equals, _)) = self.peek() {
+                        self.bump();
+                        match self.bump() {
+                            Some((Token::String(value), _)) => Ok(Cfg::KeyValue(name, value)),
+                            Some((token, pos)) => Err(CfgParseError {
+                                message: format!("expected a quoted string, found `{token:?}`"),
+                                position: pos,
+                            }),
+                            None => Err(CfgParseError {
+                                message: "expected a quoted string, found end of input".into(),
+                                position: pos,
+                            }),
+                        }
+                    } else {
+                        Ok(Cfg::Name(name))
+                    }
+                }
+            },
+            Some((token, pos)) => Err(CfgParseError {
+                message: format!("expected an identifier, found `{token:?}`"),
+                position: pos,
+            }),
+            None => Err(CfgParseError {
+                message: "expected an identifier, found end of input".into(),
+                position: 0,
+            }),
+        }
+    }
+
+    fn parse_list(&mut self) -> Result<Vec<Cfg>, CfgParseError> {
+        self.expect(&Token::LeftParen)?;
+        let mut items = Vec::new();
+        if matches!(self.peek(), Some((Token::RightParen, _))) {
+            self.bump();
+            return Ok(items);
+        }
+        loop {
+            items.push(self.parse_cfg()?);
+            match self.peek() {
+                Some((Token::Comma, _)) => {
+                    self.bump();
+                    if matches!(self.peek(), Some((Token::RightParen, _))) {
+                        break;
+                    }
+                }
+                _ => break,
+            }
+        }
+        self.expect(&Token::RightParen)?;
+        Ok(items)
+    }
+}
+
+/// Parses a Cargo-style `cfg(...)` expression into a [`Cfg`] AST.
+pub fn parse_cfg(expr: &str) -> Result<Cfg, CfgParseError> {
+    let tokens = tokenize(expr)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let cfg = parser.parse_cfg()?;
+    if let Some((token, pos)) = parser.peek() {
+        return Err(CfgParseError {
+            message: format!("unexpected trailing token `{token:?}`"),
+            position: *pos,
+        });
+    }
+    Ok(cfg)
+}
+
+fn eval(cfg: &Cfg) -> bool {
+    match cfg {
+        Cfg::Name(name) => match name.as_str() {
+            "unix" => cfg!(unix),
+            "windows" => cfg!(windows),
+            _ => false,
+        },
+        Cfg::KeyValue(key, value) => match key.as_str() {
+            "target_os" => std::env::consts::OS == value,
+            "target_arch" => std::env::consts::ARCH == value,
+            "target_family" => std::env::consts::FAMILY == value,
+            _ => false,
+        },
+        Cfg::All(cfgs) => cfgs.iter().all(eval),
+        Cfg::Any(cfgs) => cfgs.iter().any(eval),
+        Cfg::Not(cfg) => !eval(cfg),
+    }
+}
+
+/// Parses and evaluates a Cargo-style `cfg(...)` predicate against the
+/// current runner, e.g.
+/// `all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64"))`.
+pub fn matches(expr: &str) -> Result<bool, Box<dyn Error>> {
+    Ok(eval(&parse_cfg(expr)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_name_and_key_value() {
+        assert_eq!(parse_cfg("unix").unwrap(), Cfg::Name("unix".into()));
+        assert_eq!(
+            parse_cfg("target_os = \"linux\"").unwrap(),
+            Cfg::KeyValue("target_os".into(), "linux".into())
+        );
+    }
+
+    #[test]
+    fn parses_nested_all_any_not() {
+        let cfg = parse_cfg(
+            "all(target_os = \"linux\", any(target_arch = \"x86_64\", target_arch = \"aarch64\"))",
+        )
+        .unwrap();
+        assert_eq!(
+            cfg,
+            Cfg::All(vec![
+                Cfg::KeyValue("target_os".into(), "linux".into()),
+                Cfg::Any(vec![
+                    Cfg::KeyValue("target_arch".into(), "x86_64".into()),
+                    Cfg::KeyValue("target_arch".into(), "aarch64".into()),
+                ]),
+            ])
+        );
+        assert_eq!(parse_cfg("not(windows)").unwrap(), Cfg::Not(Box::new(Cfg::Name("windows".into()))));
+    }
+
+    #[test]
+    fn empty_all_and_any_are_identities() {
+        assert!(eval(&Cfg::All(vec![])));
+        assert!(!eval(&Cfg::Any(vec![])));
+    }
+
+    #[test]
+    fn matches_current_os_and_arch() {
+        let expr = format!(
+            "all(target_os = \"{}\", target_arch = \"{}\")",
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        );
+        assert!(matches(&expr).unwrap());
+        assert!(!matches("target_os = \"does-not-exist\"").unwrap());
+    }
+
+    #[test]
+    fn reports_parse_error_position() {
+        let err = parse_cfg("all(target_os = )").unwrap_err();
+        assert_eq!(err.position, 16);
+    }
+}
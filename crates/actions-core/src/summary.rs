@@ -0,0 +1,301 @@
+//! Build and write a GitHub Actions job summary, modeled on
+//! [@actions/core's summary API](https://github.com/actions/toolkit/tree/main/packages/core#markdown-summary).
+
+use crate::CoreError;
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write as _;
+
+pub const SUMMARY: &str = "GITHUB_STEP_SUMMARY";
+pub const MARKDOWN_SUMMARY: &str = "GITHUB_STEP_SUMMARY";
+
+/// A single `<td>`/`<th>` cell in a [`Summary::add_table`] row.
+pub struct TableCell {
+    pub data: String,
+    pub header: bool,
+    pub colspan: Option<u32>,
+    pub rowspan: Option<u32>,
+}
+
+impl TableCell {
+    pub fn new(data: impl Into<String>) -> Self {
+        TableCell {
+            data: data.into(),
+            header: false,
+            colspan: None,
+            rowspan: None,
+        }
+    }
+}
+
+impl From<&str> for TableCell {
+    fn from(data: &str) -> Self {
+        TableCell::new(data)
+    }
+}
+
+impl From<String> for TableCell {
+    fn from(data: String) -> Self {
+        TableCell::new(data)
+    }
+}
+
+/// A Markdown/HTML job summary buffer, written to the file named by the
+/// `GITHUB_STEP_SUMMARY` environment variable.
+#[derive(Default)]
+pub struct Summary {
+    buffer: String,
+}
+
+impl Summary {
+    pub fn new() -> Self {
+        Summary::default()
+    }
+
+    /// Returns the buffered summary content without writing it.
+    pub fn stringify(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Returns `true` if nothing has been added to the buffer yet.
+    pub fn is_empty_buffer(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Empties the buffer and clears the summary file on disk.
+    pub fn clear(&mut self) -> Result<(), CoreError> {
+        self.buffer.clear();
+        self.write(true)
+    }
+
+    /// Appends raw text to the buffer, optionally followed by an EOL.
+    pub fn add_raw(&mut self, text: &str, add_eol: bool) -> &mut Self {
+        self.buffer.push_str(text);
+        if add_eol {
+            self.add_eol();
+        }
+        self
+    }
+
+    /// Appends an EOL to the buffer.
+    pub fn add_eol(&mut self) -> &mut Self {
+        self.add_raw("\n", false)
+    }
+
+    /// Appends a code block, optionally annotated with its language.
+    pub fn add_code_block(&mut self, code: &str, lang: Option<&str>) -> &mut Self {
+        let attr = lang
+            .map(|lang| format!(" lang=\"{lang}\""))
+            .unwrap_or_default();
+        let element = format!("<pre{attr}><code>{code}</code></pre>");
+        self.add_raw(&element, true)
+    }
+
+    /// Appends an ordered or unordered list.
+    pub fn add_list<S: AsRef<str>>(&mut self, items: &[S], ordered: bool) -> &mut Self {
+        let tag = if ordered { "ol" } else { "ul" };
+        let items = items
+            .iter()
+            .map(|item| format!("<li>{}</li>", item.as_ref()))
+            .collect::<String>();
+        let element = format!("<{tag}>{items}</{tag}>");
+        self.add_raw(&element, true)
+    }
+
+    /// Appends an HTML table. Each row is a `Vec` of cells (header/colspan/
+    /// rowspan are taken from the [`TableCell`]; a `&str` or `String` converts
+    /// into a plain data cell with `.into()`).
+    pub fn add_table(&mut self, rows: Vec<Vec<TableCell>>) -> &mut Self {
+        let rows = rows
+            .into_iter()
+            .map(|cells| {
+                let cells = cells
+                    .into_iter()
+                    .map(|cell| {
+                        let tag = if cell.header { "th" } else { "td" };
+                        let colspan = cell
+                            .colspan
+                            .map(|n| format!(" colspan=\"{n}\""))
+                            .unwrap_or_default();
+                        let rowspan = cell
+                            .rowspan
+                            .map(|n| format!(" rowspan=\"{n}\""))
+                            .unwrap_or_default();
+                        format!("<{tag}{colspan}{rowspan}>{}</{tag}>", cell.data)
+                    })
+                    .collect::<String>();
+                format!("<tr>{cells}</tr>")
+            })
+            .collect::<String>();
+        let element = format!("<table>{rows}</table>");
+        self.add_raw(&element, true)
+    }
+
+    /// Appends a heading, clamping `level` to the valid `1..=6` range.
+    pub fn add_heading(&mut self, text: &str, level: u32) -> &mut Self {
+        let level = level.clamp(1, 6);
+        let element = format!("<h{level}>{text}</h{level}>");
+        self.add_raw(&element, true)
+    }
+
+    /// Appends an image, with optional `width`/`height` attributes.
+    pub fn add_image(
+        &mut self,
+        src: &str,
+        alt: &str,
+        width: Option<u32>,
+        height: Option<u32>,
+    ) -> &mut Self {
+        let width = width
+            .map(|w| format!(" width=\"{w}\""))
+            .unwrap_or_default();
+        let height = height
+            .map(|h| format!(" height=\"{h}\""))
+            .unwrap_or_default();
+        let element = format!("<img src=\"{src}\" alt=\"{alt}\"{width}{height}>");
+        self.add_raw(&element, true)
+    }
+
+    /// Appends a hyperlink.
+    pub fn add_link(&mut self, text: &str, href: &str) -> &mut Self {
+        let element = format!("<a href=\"{href}\">{text}</a>");
+        self.add_raw(&element, true)
+    }
+
+    /// Appends a horizontal rule.
+    pub fn add_separator(&mut self) -> &mut Self {
+        self.add_raw("<hr>", true)
+    }
+
+    /// Appends a block quote.
+    pub fn add_quote(&mut self, text: &str) -> &mut Self {
+        let element = format!("<blockquote>{text}</blockquote>");
+        self.add_raw(&element, true)
+    }
+
+    /// Appends a line break.
+    pub fn add_break(&mut self) -> &mut Self {
+        self.add_raw("<br>", true)
+    }
+
+    /// Writes the buffered content to the file named by `GITHUB_STEP_SUMMARY`,
+    /// then clears the in-memory buffer. Set `overwrite` to replace the
+    /// file's contents instead of appending to it.
+    pub fn write(&mut self, overwrite: bool) -> Result<(), CoreError> {
+        let file_path = env::var("GITHUB_STEP_SUMMARY")
+            .map_err(|_| CoreError::Other("GITHUB_STEP_SUMMARY environment variable is not set".into()))?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(!overwrite)
+            .truncate(overwrite)
+            .open(&file_path)
+            .map_err(|err| {
+                CoreError::Other(format!("failed to open {file_path} for writing: {err}"))
+            })?;
+        file.write_all(self.buffer.as_bytes())?;
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // `write` reads `GITHUB_STEP_SUMMARY` from the process environment, so
+    // tests that set it must not run concurrently with each other.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn temp_summary_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("actions_core_summary_test_{}_{name}", std::process::id()))
+    }
+
+    #[test]
+    fn add_heading_list_and_table_emit_html() {
+        let mut summary = Summary::new();
+        summary
+            .add_heading("Results", 2)
+            .add_list(&["a", "b"], false)
+            .add_table(vec![
+                vec![TableCell::new("Name"), {
+                    let mut cell = TableCell::new("Status");
+                    cell.header = true;
+                    cell
+                }],
+                vec!["ok".into(), "pass".into()],
+            ]);
+        let out = summary.stringify();
+        assert!(out.contains("<h2>Results</h2>"));
+        assert!(out.contains("<ul><li>a</li><li>b</li></ul>"));
+        assert!(out.contains("<table><tr><td>Name</td><th>Status</th></tr><tr><td>ok</td><td>pass</td></tr></table>"));
+    }
+
+    #[test]
+    fn add_code_block_includes_lang_attribute() {
+        let mut summary = Summary::new();
+        summary.add_code_block("let x = 1;", Some("rust"));
+        assert!(summary
+            .stringify()
+            .contains("<pre lang=\"rust\"><code>let x = 1;</code></pre>"));
+    }
+
+    #[test]
+    fn write_false_appends_to_existing_file() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let path = temp_summary_path("append");
+        let _ = std::fs::remove_file(&path);
+        std::env::set_var("GITHUB_STEP_SUMMARY", &path);
+
+        Summary::new().add_raw("first", true).write(false).unwrap();
+        Summary::new().add_raw("second", true).write(false).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents, "first\nsecond\n");
+
+        std::env::remove_var("GITHUB_STEP_SUMMARY");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn write_true_truncates_existing_file() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let path = temp_summary_path("truncate");
+        let _ = std::fs::remove_file(&path);
+        std::env::set_var("GITHUB_STEP_SUMMARY", &path);
+
+        Summary::new().add_raw("first", true).write(false).unwrap();
+        Summary::new().add_raw("second", true).write(true).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents, "second\n");
+
+        std::env::remove_var("GITHUB_STEP_SUMMARY");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn write_clears_the_in_memory_buffer() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let path = temp_summary_path("clear_buffer");
+        let _ = std::fs::remove_file(&path);
+        std::env::set_var("GITHUB_STEP_SUMMARY", &path);
+
+        let mut summary = Summary::new();
+        summary.add_raw("buffered", true).write(true).unwrap();
+        assert!(summary.is_empty_buffer());
+
+        std::env::remove_var("GITHUB_STEP_SUMMARY");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn write_errors_when_env_var_is_unset() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("GITHUB_STEP_SUMMARY");
+
+        let err = Summary::new().add_raw("x", false).write(false).unwrap_err();
+        assert!(matches!(err, CoreError::Other(_)));
+    }
+}